@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TicTacToeError {
+    TileOutOfBounds,
+    TileAlreadySet,
+    GameAlreadyOver,
+    NotPlayersTurn,
+    GameAlreadyStarted,
+    #[msg("The escrow account's mint does not match the game's staked mint")]
+    MintMismatch,
+    #[msg("The game is still active; winnings cannot be claimed yet")]
+    GameStillActive,
+    #[msg("Signer is not a registered player of this game")]
+    NotAPlayer,
+    #[msg("Only the registry authority may perform this action")]
+    Unauthorized,
+    #[msg("Board dimensions are invalid or exceed the maximum area")]
+    InvalidBoardSize,
+    #[msg("Win length must not exceed the larger board dimension")]
+    InvalidWinLength,
+    #[msg("The turn timeout has not yet elapsed")]
+    TimeoutNotReached,
+    #[msg("Player two must match the wager before the game can proceed")]
+    StakeNotMatched,
+    #[msg("The staked wager has already been matched")]
+    StakeAlreadyMatched,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::game::{GameState, Tile};
+
+/// Emitted once when a match is created, carrying its two players.
+#[event]
+pub struct GameCreated {
+    pub game: Pubkey,
+    pub players: [Pubkey; 2],
+}
+
+/// Emitted for every accepted move. `state` is the resolved game state *after*
+/// the move, so a single log line tells a client whether the move ended the
+/// game.
+#[event]
+pub struct MoveMade {
+    pub game: Pubkey,
+    pub player: Pubkey,
+    pub tile: Tile,
+    pub turn: u8,
+    pub state: GameState,
+}
+
+/// Emitted when a move or a timeout forfeit resolves the game, carrying the
+/// terminal state.
+#[event]
+pub struct GameEnded {
+    pub game: Pubkey,
+    pub state: GameState,
+}
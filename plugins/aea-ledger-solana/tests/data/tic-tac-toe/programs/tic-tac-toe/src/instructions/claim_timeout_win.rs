@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TicTacToeError;
+use crate::events::GameEnded;
+use crate::state::game::Game;
+use crate::state::registry::{PlayerStats, Registry};
+
+pub fn claim_timeout_win(ctx: Context<ClaimTimeoutWin>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let claimant = ctx.accounts.player.key();
+
+    let players = game.players();
+    require!(
+        claimant == players[0] || claimant == players[1],
+        TicTacToeError::NotAPlayer
+    );
+    require!(game.is_active(), TicTacToeError::GameAlreadyOver);
+
+    // The stalling side is whoever must move next; the claimant must be the
+    // waiting opponent, not the player sitting on their own turn.
+    require_keys_neq!(
+        game.current_player(),
+        claimant,
+        TicTacToeError::NotPlayersTurn
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now - game.last_move_ts() > game.turn_timeout(),
+        TicTacToeError::TimeoutNotReached
+    );
+
+    game.force_win(claimant);
+
+    // Apply the same resolution bookkeeping as `play`: decrement the active
+    // counter, update both players' tallies, and log the outcome.
+    let registry = &mut ctx.accounts.registry;
+    registry.active_games = registry.active_games.saturating_sub(1);
+
+    let one = &mut ctx.accounts.player_one_stats;
+    let two = &mut ctx.accounts.player_two_stats;
+    one.player = players[0];
+    two.player = players[1];
+    if claimant == players[0] {
+        one.wins += 1;
+        two.losses += 1;
+    } else {
+        two.wins += 1;
+        one.losses += 1;
+    }
+
+    emit!(GameEnded {
+        game: ctx.accounts.game.key(),
+        state: ctx.accounts.game.state().clone(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimTimeoutWin<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(mut, seeds = [Registry::SEED], bump)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerStats::SIZE,
+        seeds = [PlayerStats::SEED, game.players()[0].as_ref()],
+        bump,
+    )]
+    pub player_one_stats: Account<'info, PlayerStats>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerStats::SIZE,
+        seeds = [PlayerStats::SEED, game.players()[1].as_ref()],
+        bump,
+    )]
+    pub player_two_stats: Account<'info, PlayerStats>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::TicTacToeError;
+use crate::state::game::{Game, GameState};
+
+pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
+    let game = &ctx.accounts.game;
+
+    require!(!game.is_active(), TicTacToeError::GameStillActive);
+    require_keys_eq!(
+        ctx.accounts.escrow.mint,
+        game.mint,
+        TicTacToeError::MintMismatch
+    );
+
+    // Only a registered player may trigger the payout.
+    let claimant = ctx.accounts.claimant.key();
+    let players = game.players();
+    require!(
+        claimant == players[0] || claimant == players[1],
+        TicTacToeError::NotAPlayer
+    );
+
+    let game_key = game.key();
+    let seeds: &[&[u8]] = &[b"escrow", game_key.as_ref(), &[game.escrow_bump]];
+    let signer = &[seeds];
+    let pot = ctx.accounts.escrow.amount;
+
+    match game.state() {
+        GameState::Won { winner } => {
+            // The token-account owner constraints bind each destination to a
+            // registered player; route the whole pot to the winner's.
+            let to = if *winner == players[0] {
+                ctx.accounts.player_one_token.to_account_info()
+            } else {
+                ctx.accounts.player_two_token.to_account_info()
+            };
+            transfer_from_escrow(&ctx, to, pot, signer)?;
+        }
+        GameState::Tie => {
+            // Split the pot back to both players; player one absorbs the odd
+            // token when the pot is not evenly divisible.
+            let half = pot / 2;
+            transfer_from_escrow(
+                &ctx,
+                ctx.accounts.player_two_token.to_account_info(),
+                half,
+                signer,
+            )?;
+            transfer_from_escrow(
+                &ctx,
+                ctx.accounts.player_one_token.to_account_info(),
+                pot - half,
+                signer,
+            )?;
+        }
+        GameState::Active => return err!(TicTacToeError::GameStillActive),
+    }
+
+    Ok(())
+}
+
+fn transfer_from_escrow<'info>(
+    ctx: &Context<ClaimWinnings<'info>>,
+    to: AccountInfo<'info>,
+    amount: u64,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.escrow.to_account_info(),
+                to,
+                authority: ctx.accounts.escrow.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ClaimWinnings<'info> {
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"escrow", game.key().as_ref()],
+        bump = game.escrow_bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    /// Must be one of the two registered players.
+    pub claimant: Signer<'info>,
+    #[account(mut, constraint = player_one_token.owner == game.players()[0] @ TicTacToeError::NotAPlayer)]
+    pub player_one_token: Account<'info, TokenAccount>,
+    #[account(mut, constraint = player_two_token.owner == game.players()[1] @ TicTacToeError::NotAPlayer)]
+    pub player_two_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
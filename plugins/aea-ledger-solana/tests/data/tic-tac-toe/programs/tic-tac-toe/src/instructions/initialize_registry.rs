@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::registry::Registry;
+
+pub fn initialize_registry(ctx: Context<InitializeRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    registry.authority = ctx.accounts.authority.key();
+    registry.total_games = 0;
+    registry.active_games = 0;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRegistry<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Registry::SIZE,
+        seeds = [Registry::SEED],
+        bump,
+    )]
+    pub registry: Account<'info, Registry>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::TicTacToeError;
+use crate::state::game::Game;
+
+pub fn join_staked_game(ctx: Context<JoinStakedGame>) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+
+    require!(game.is_staked(), TicTacToeError::StakeNotMatched);
+    require!(!game.is_funded(), TicTacToeError::StakeAlreadyMatched);
+    require_keys_eq!(
+        ctx.accounts.player_two.key(),
+        game.players()[1],
+        TicTacToeError::NotAPlayer
+    );
+    require_keys_eq!(
+        ctx.accounts.escrow.mint,
+        game.mint,
+        TicTacToeError::MintMismatch
+    );
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_two_token.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.player_two.to_account_info(),
+            },
+        ),
+        game.stake,
+    )?;
+
+    game.mark_funded();
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct JoinStakedGame<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"escrow", game.key().as_ref()],
+        bump = game.escrow_bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub player_two: Signer<'info>,
+    #[account(mut, constraint = player_two_token.mint == game.mint @ TicTacToeError::MintMismatch)]
+    pub player_two_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
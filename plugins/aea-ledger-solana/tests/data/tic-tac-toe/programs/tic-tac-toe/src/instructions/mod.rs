@@ -0,0 +1,19 @@
+pub mod claim_timeout_win;
+pub mod claim_winnings;
+pub mod initialize_registry;
+pub mod join_staked_game;
+pub mod play;
+pub mod reset_player_stats;
+pub mod reset_registry;
+pub mod setup_game;
+pub mod setup_staked_game;
+
+pub use claim_timeout_win::*;
+pub use claim_winnings::*;
+pub use initialize_registry::*;
+pub use join_staked_game::*;
+pub use play::*;
+pub use reset_player_stats::*;
+pub use reset_registry::*;
+pub use setup_game::*;
+pub use setup_staked_game::*;
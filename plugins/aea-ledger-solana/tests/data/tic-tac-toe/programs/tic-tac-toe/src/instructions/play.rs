@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TicTacToeError;
+use crate::events::{GameEnded, MoveMade};
+use crate::state::game::{Game, GameState, Tile};
+use crate::state::registry::{PlayerStats, Registry};
+
+pub fn play(ctx: Context<Play>, tile: Tile) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+
+    require_keys_eq!(
+        game.current_player(),
+        ctx.accounts.player.key(),
+        TicTacToeError::NotPlayersTurn
+    );
+
+    // On a staked game, player two's first move (and everything after it) is
+    // gated behind their matching deposit via `join_staked_game`.
+    if game.is_staked() && !game.is_funded() {
+        require_keys_eq!(
+            game.current_player(),
+            game.players()[0],
+            TicTacToeError::StakeNotMatched
+        );
+    }
+
+    let was_active = game.is_active();
+    game.play(&tile)?;
+    game.record_move(Clock::get()?.unix_timestamp);
+
+    // A move that resolves the game updates the canonical counters and the
+    // per-player leaderboard exactly once.
+    if was_active && !game.is_active() {
+        let players = game.players();
+        let registry = &mut ctx.accounts.registry;
+        registry.active_games = registry.active_games.saturating_sub(1);
+
+        let one = &mut ctx.accounts.player_one_stats;
+        let two = &mut ctx.accounts.player_two_stats;
+        one.player = players[0];
+        two.player = players[1];
+
+        match game.winner() {
+            Some(winner) if winner == players[0] => {
+                one.wins += 1;
+                two.losses += 1;
+            }
+            Some(_) => {
+                two.wins += 1;
+                one.losses += 1;
+            }
+            None => {
+                one.ties += 1;
+                two.ties += 1;
+            }
+        }
+    }
+
+    let game = &ctx.accounts.game;
+    let state = game.state().clone();
+    let move_event = MoveMade {
+        game: game.key(),
+        player: ctx.accounts.player.key(),
+        tile,
+        turn: game.turn(),
+        state: state.clone(),
+    };
+    let ended = !matches!(state, GameState::Active);
+    emit!(move_event);
+    if ended {
+        emit!(GameEnded {
+            game: game.key(),
+            state,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Play<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    #[account(mut, seeds = [Registry::SEED], bump)]
+    pub registry: Account<'info, Registry>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerStats::SIZE,
+        seeds = [PlayerStats::SEED, game.players()[0].as_ref()],
+        bump,
+    )]
+    pub player_one_stats: Account<'info, PlayerStats>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerStats::SIZE,
+        seeds = [PlayerStats::SEED, game.players()[1].as_ref()],
+        bump,
+    )]
+    pub player_two_stats: Account<'info, PlayerStats>,
+    pub system_program: Program<'info, System>,
+}
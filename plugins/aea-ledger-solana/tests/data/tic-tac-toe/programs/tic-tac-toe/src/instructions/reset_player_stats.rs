@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TicTacToeError;
+use crate::state::registry::{PlayerStats, Registry};
+
+/// Authority-gated reset of a single player's leaderboard tally. `reset_registry`
+/// only zeroes the aggregate counters; the per-player PDAs are wiped one at a
+/// time through this instruction.
+pub fn reset_player_stats(ctx: Context<ResetPlayerStats>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.registry.authority,
+        ctx.accounts.authority.key(),
+        TicTacToeError::Unauthorized
+    );
+
+    let stats = &mut ctx.accounts.player_stats;
+    stats.wins = 0;
+    stats.losses = 0;
+    stats.ties = 0;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResetPlayerStats<'info> {
+    #[account(seeds = [Registry::SEED], bump)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut, seeds = [PlayerStats::SEED, player_stats.player.as_ref()], bump)]
+    pub player_stats: Account<'info, PlayerStats>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TicTacToeError;
+use crate::state::registry::Registry;
+
+pub fn reset_registry(ctx: Context<ResetRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    require_keys_eq!(
+        registry.authority,
+        ctx.accounts.authority.key(),
+        TicTacToeError::Unauthorized
+    );
+    registry.total_games = 0;
+    registry.active_games = 0;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResetRegistry<'info> {
+    #[account(mut, seeds = [Registry::SEED], bump)]
+    pub registry: Account<'info, Registry>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::events::GameCreated;
+use crate::state::game::Game;
+use crate::state::registry::Registry;
+
+pub fn setup_game(
+    ctx: Context<SetupGame>,
+    player_two: Pubkey,
+    rows: u8,
+    cols: u8,
+    win_len: u8,
+    turn_timeout: i64,
+) -> Result<()> {
+    ctx.accounts.game.start_sized(
+        [ctx.accounts.player_one.key(), player_two],
+        rows,
+        cols,
+        win_len,
+    )?;
+    ctx.accounts
+        .game
+        .arm_clock(Clock::get()?.unix_timestamp, turn_timeout);
+
+    let registry = &mut ctx.accounts.registry;
+    registry.total_games += 1;
+    registry.active_games += 1;
+
+    emit!(GameCreated {
+        game: ctx.accounts.game.key(),
+        players: ctx.accounts.game.players(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetupGame<'info> {
+    #[account(init, payer = player_one, space = 8 + Game::MAXIMUM_SIZE)]
+    pub game: Account<'info, Game>,
+    #[account(mut, seeds = [Registry::SEED], bump)]
+    pub registry: Account<'info, Registry>,
+    #[account(mut)]
+    pub player_one: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::TicTacToeError;
+use crate::events::GameCreated;
+use crate::state::game::Game;
+use crate::state::registry::Registry;
+
+pub fn setup_staked_game(
+    ctx: Context<SetupStakedGame>,
+    player_two: Pubkey,
+    wager: u64,
+    mint: Pubkey,
+    turn_timeout: i64,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.escrow.mint,
+        mint,
+        TicTacToeError::MintMismatch
+    );
+
+    let game = &mut ctx.accounts.game;
+    game.start([ctx.accounts.player_one.key(), player_two])?;
+    game.stake = wager;
+    game.mint = mint;
+    game.escrow_bump = ctx.bumps.escrow;
+    game.arm_clock(Clock::get()?.unix_timestamp, turn_timeout);
+
+    let registry = &mut ctx.accounts.registry;
+    registry.total_games += 1;
+    registry.active_games += 1;
+
+    emit!(GameCreated {
+        game: ctx.accounts.game.key(),
+        players: ctx.accounts.game.players(),
+    });
+
+    // Player one funds the pot up front; player two matches it via
+    // `join_staked_game` before the game may proceed past player one's opening
+    // move.
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_one_token.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.player_one.to_account_info(),
+            },
+        ),
+        wager,
+    )
+}
+
+#[derive(Accounts)]
+pub struct SetupStakedGame<'info> {
+    #[account(init, payer = player_one, space = 8 + Game::MAXIMUM_SIZE)]
+    pub game: Account<'info, Game>,
+    /// PDA-owned escrow holding the pot; it is its own token authority so
+    /// that only `claim_winnings` can move funds out, signing with the
+    /// seeds derived from the game key.
+    #[account(
+        init,
+        payer = player_one,
+        seeds = [b"escrow", game.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+    #[account(mut, seeds = [Registry::SEED], bump)]
+    pub registry: Account<'info, Registry>,
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub player_one: Signer<'info>,
+    #[account(mut, constraint = player_one_token.mint == mint.key() @ TicTacToeError::MintMismatch)]
+    pub player_one_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
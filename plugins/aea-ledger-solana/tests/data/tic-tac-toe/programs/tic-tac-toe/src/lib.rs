@@ -3,6 +3,7 @@ use instructions::*;
 use state::game::Tile;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -13,11 +14,58 @@ declare_id!("AT9foczNVnZCLyxuHr2PoVKswZV84jhXrvV3H3vYeEag");
 pub mod tic_tac_toe {
     use super::*;
 
-    pub fn setup_game(ctx: Context<SetupGame>, player_two: Pubkey) -> Result<()> {
-        instructions::setup_game::setup_game(ctx, player_two)
+    pub fn setup_game(
+        ctx: Context<SetupGame>,
+        player_two: Pubkey,
+        rows: u8,
+        cols: u8,
+        win_len: u8,
+        turn_timeout: i64,
+    ) -> Result<()> {
+        instructions::setup_game::setup_game(ctx, player_two, rows, cols, win_len, turn_timeout)
+    }
+
+    pub fn initialize_registry(ctx: Context<InitializeRegistry>) -> Result<()> {
+        instructions::initialize_registry::initialize_registry(ctx)
+    }
+
+    pub fn reset_registry(ctx: Context<ResetRegistry>) -> Result<()> {
+        instructions::reset_registry::reset_registry(ctx)
+    }
+
+    pub fn reset_player_stats(ctx: Context<ResetPlayerStats>) -> Result<()> {
+        instructions::reset_player_stats::reset_player_stats(ctx)
+    }
+
+    pub fn setup_staked_game(
+        ctx: Context<SetupStakedGame>,
+        player_two: Pubkey,
+        wager: u64,
+        mint: Pubkey,
+        turn_timeout: i64,
+    ) -> Result<()> {
+        instructions::setup_staked_game::setup_staked_game(
+            ctx,
+            player_two,
+            wager,
+            mint,
+            turn_timeout,
+        )
+    }
+
+    pub fn join_staked_game(ctx: Context<JoinStakedGame>) -> Result<()> {
+        instructions::join_staked_game::join_staked_game(ctx)
     }
 
     pub fn play(ctx: Context<Play>, tile: Tile) -> Result<()> {
         instructions::play::play(ctx, tile)
     }
+
+    pub fn claim_winnings(ctx: Context<ClaimWinnings>) -> Result<()> {
+        instructions::claim_winnings::claim_winnings(ctx)
+    }
+
+    pub fn claim_timeout_win(ctx: Context<ClaimTimeoutWin>) -> Result<()> {
+        instructions::claim_timeout_win::claim_timeout_win(ctx)
+    }
 }
@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::FromPrimitive;
+
+use crate::errors::TicTacToeError;
+
+#[account]
+pub struct Game {
+    players: [Pubkey; 2],       // 64
+    turn: u8,                   // 1
+    rows: u8,                   // 1
+    cols: u8,                   // 1
+    win_len: u8,                // 1
+    board: Vec<Option<Sign>>,   // 4 + rows*cols * (1 + 1)
+    state: GameState,           // 32 + 1
+    pub stake: u64,             // 8
+    pub mint: Pubkey,           // 32
+    pub escrow_bump: u8,        // 1
+    last_move_ts: i64,          // 8
+    turn_timeout: i64,          // 8
+    funded: bool,               // 1
+}
+
+impl Game {
+    /// Largest board we are willing to rent account space for. Keeps the
+    /// generalized m,n,k board within a sane account size.
+    pub const MAX_AREA: usize = 100;
+
+    pub const MAXIMUM_SIZE: usize = (32 * 2)
+        + 1
+        + 1
+        + 1
+        + 1
+        + (4 + Self::MAX_AREA * (1 + 1))
+        + (32 + 1)
+        + 8
+        + 32
+        + 1
+        + 8
+        + 8
+        + 1;
+
+    /// Start a classic 3×3, three-in-a-row game. Kept for callers that do not
+    /// care about the generalized board.
+    pub fn start(&mut self, players: [Pubkey; 2]) -> Result<()> {
+        self.start_sized(players, 3, 3, 3)
+    }
+
+    /// Start a generalized m,n,k-game on a `rows`×`cols` board won by
+    /// `win_len` contiguous signs in any direction.
+    pub fn start_sized(
+        &mut self,
+        players: [Pubkey; 2],
+        rows: u8,
+        cols: u8,
+        win_len: u8,
+    ) -> Result<()> {
+        require_eq!(self.turn, 0, TicTacToeError::GameAlreadyStarted);
+        require!(
+            rows >= 1 && cols >= 1 && win_len >= 1,
+            TicTacToeError::InvalidBoardSize
+        );
+        let area = (rows as usize) * (cols as usize);
+        require!(area <= Self::MAX_AREA, TicTacToeError::InvalidBoardSize);
+        require!(
+            (win_len as usize) <= rows.max(cols) as usize,
+            TicTacToeError::InvalidWinLength
+        );
+
+        self.players = players;
+        self.turn = 1;
+        self.rows = rows;
+        self.cols = cols;
+        self.win_len = win_len;
+        self.board = vec![None; area];
+        Ok(())
+    }
+
+    /// Arm the turn clock at game creation. `now` comes from the `Clock`
+    /// sysvar; `turn_timeout` is the number of seconds a player may take
+    /// before the opponent can claim a forfeit.
+    pub fn arm_clock(&mut self, now: i64, turn_timeout: i64) {
+        self.last_move_ts = now;
+        self.turn_timeout = turn_timeout;
+    }
+
+    /// Refresh the move timestamp; called after every accepted move.
+    pub fn record_move(&mut self, now: i64) {
+        self.last_move_ts = now;
+    }
+
+    pub fn last_move_ts(&self) -> i64 {
+        self.last_move_ts
+    }
+
+    pub fn turn_timeout(&self) -> i64 {
+        self.turn_timeout
+    }
+
+    /// Resolve the game in favour of `winner` without a winning line; used by
+    /// the timeout forfeit path.
+    pub fn force_win(&mut self, winner: Pubkey) {
+        self.state = GameState::Won { winner };
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == GameState::Active
+    }
+
+    fn current_player_index(&self) -> usize {
+        ((self.turn - 1) % 2) as usize
+    }
+
+    pub fn current_player(&self) -> Pubkey {
+        self.players[self.current_player_index()]
+    }
+
+    pub fn turn(&self) -> u8 {
+        self.turn
+    }
+
+    /// Whether this game carries a wager. Whether player two has matched it
+    /// is reported separately by `is_funded`.
+    pub fn is_staked(&self) -> bool {
+        self.stake > 0
+    }
+
+    pub fn is_funded(&self) -> bool {
+        self.funded
+    }
+
+    pub fn mark_funded(&mut self) {
+        self.funded = true;
+    }
+
+    pub fn players(&self) -> [Pubkey; 2] {
+        self.players
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// The winner of a resolved game, or `None` while the game is still
+    /// `Active` or ended in a `Tie`.
+    pub fn winner(&self) -> Option<Pubkey> {
+        match self.state {
+            GameState::Won { winner } => Some(winner),
+            _ => None,
+        }
+    }
+
+    fn index(&self, row: u8, column: u8) -> usize {
+        (row as usize) * (self.cols as usize) + (column as usize)
+    }
+
+    pub fn play(&mut self, tile: &Tile) -> Result<()> {
+        require!(self.is_active(), TicTacToeError::GameAlreadyOver);
+
+        if tile.row >= self.rows || tile.column >= self.cols {
+            return Err(TicTacToeError::TileOutOfBounds.into());
+        }
+
+        let idx = self.index(tile.row, tile.column);
+        match self.board[idx] {
+            Some(_) => return Err(TicTacToeError::TileAlreadySet.into()),
+            None => {
+                self.board[idx] = Some(Sign::from_usize(self.current_player_index()).unwrap());
+            }
+        }
+
+        self.update_state(tile);
+
+        if GameState::Active == self.state {
+            self.turn += 1;
+        }
+
+        Ok(())
+    }
+
+    fn sign_at(&self, row: i32, column: i32) -> Option<Sign> {
+        if row < 0 || column < 0 || row >= self.rows as i32 || column >= self.cols as i32 {
+            return None;
+        }
+        self.board[self.index(row as u8, column as u8)]
+    }
+
+    /// Count contiguous tiles matching `sign` stepping `(d_row, d_col)` away
+    /// from `(row, col)`, not including the starting tile itself.
+    fn run_length(&self, row: i32, column: i32, d_row: i32, d_col: i32, sign: Sign) -> usize {
+        let mut count = 0;
+        let (mut r, mut c) = (row + d_row, column + d_col);
+        while self.sign_at(r, c) == Some(sign) {
+            count += 1;
+            r += d_row;
+            c += d_col;
+        }
+        count
+    }
+
+    /// Starting from the just-played tile, scan the four direction pairs
+    /// (horizontal, vertical, and both diagonals). A win is declared when the
+    /// contiguous run through the tile reaches `win_len` in any pair.
+    fn update_state(&mut self, tile: &Tile) {
+        let row = tile.row as i32;
+        let column = tile.column as i32;
+        let sign = match self.sign_at(row, column) {
+            Some(sign) => sign,
+            None => return,
+        };
+
+        const DIRECTIONS: [(i32, i32); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+        for (d_row, d_col) in DIRECTIONS {
+            let run = 1
+                + self.run_length(row, column, d_row, d_col, sign)
+                + self.run_length(row, column, -d_row, -d_col, sign);
+            if run >= self.win_len as usize {
+                self.state = GameState::Won {
+                    winner: self.current_player(),
+                };
+                return;
+            }
+        }
+
+        // Not won: if any tile is still free the game continues, otherwise it
+        // is a tie.
+        if self.board.iter().any(|tile| tile.is_none()) {
+            return;
+        }
+
+        self.state = GameState::Tie;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum GameState {
+    Active,
+    Tie,
+    Won { winner: Pubkey },
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        GameState::Active
+    }
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, FromPrimitive, ToPrimitive, Copy, Clone, PartialEq, Eq,
+)]
+pub enum Sign {
+    X,
+    O,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub struct Tile {
+    row: u8,
+    column: u8,
+}
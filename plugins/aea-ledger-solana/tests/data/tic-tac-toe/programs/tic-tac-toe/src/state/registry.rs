@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Program-level singleton holding the canonical match counters and the
+/// admin authority allowed to wipe them. Lives at a fixed PDA so clients can
+/// address it without scanning accounts.
+#[account]
+#[derive(Default)]
+pub struct Registry {
+    pub authority: Pubkey,
+    pub total_games: u64,
+    pub active_games: u64,
+}
+
+impl Registry {
+    pub const SIZE: usize = 32 + 8 + 8;
+    pub const SEED: &'static [u8] = b"registry";
+}
+
+/// Companion PDA, one per player, keyed by the player's public key. Forms the
+/// on-chain leaderboard without having to read every `Game` account.
+#[account]
+#[derive(Default)]
+pub struct PlayerStats {
+    pub player: Pubkey,
+    pub wins: u64,
+    pub losses: u64,
+    pub ties: u64,
+}
+
+impl PlayerStats {
+    pub const SIZE: usize = 32 + 8 + 8 + 8;
+    pub const SEED: &'static [u8] = b"stats";
+}